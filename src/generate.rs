@@ -8,6 +8,10 @@ pub struct RenderOptions {
 	pub width: usize,
 	pub height: usize,
 	pub unit_width: f64,
+	#[serde(default)]
+	pub view_cx: f64,
+	#[serde(default)]
+	pub view_cy: f64,
 	pub max_iter: u16,
 	pub cx: f64,
 	pub cy: f64,
@@ -26,6 +30,8 @@ impl Default for RenderOptions {
 			width: 512,
 			height: 512,
 			unit_width: 4.0,
+			view_cx: 0.0,
+			view_cy: 0.0,
 			max_iter: 128,
 			cx: 0.4,
 			cy: -0.2,
@@ -43,8 +49,8 @@ pub fn render_c(q: &RenderOptions, mut image: RgbImage) -> RgbImage {
 
 	for y in 0..q.height {
 		for x in 0..q.width {
-			let sx = (x as f32 - width / 2.0) / ppu;
-			let sy = (y as f32 - height / 2.0) / ppu;
+			let sx = (x as f32 - width / 2.0) / ppu + q.view_cx as f32;
+			let sy = (y as f32 - height / 2.0) / ppu + q.view_cy as f32;
 
 			let len = (Vec2::new(sx, sy) - target).length();
 			if len < 0.03 {
@@ -57,13 +63,51 @@ pub fn render_c(q: &RenderOptions, mut image: RgbImage) -> RgbImage {
 	image
 }
 
-pub fn color_iteration(iter: u16, color: (u8, u8, u8)) -> Rgb<u8> {
-	let i = iter.min(255) as u8;
-	Rgb([
-		i.saturating_mul(color.0),
-		i.saturating_mul(color.1),
-		i.saturating_mul(color.2),
-	])
+/// How many iterations one gradient cycle spans before repeating. Keeping
+/// the gradient short relative to typical `max_iter` values is what makes
+/// the coloring vary smoothly across the whole escape-time range instead of
+/// clipping to the last stop for most of it.
+const GRADIENT_PERIOD: f64 = 64.0;
+
+/// Builds the black -> bright -> white -> bright -> black gradient stops
+/// used by [`color_iteration`], with `bright` derived from the user's
+/// `color` sliders (each 0..16, scaled so 16 reaches full brightness).
+fn gradient_stops(color: (u8, u8, u8)) -> [[f64; 3]; 5] {
+	let bright = [
+		(color.0 as f64 * 16.0 / 255.0).min(1.0),
+		(color.1 as f64 * 16.0 / 255.0).min(1.0),
+		(color.2 as f64 * 16.0 / 255.0).min(1.0),
+	];
+	[[0.0; 3], bright, [1.0; 3], bright, [0.0; 3]]
+}
+
+/// Maps the smooth escape-time value `mu` onto a cyclic gradient of color
+/// stops (see [`gradient_stops`]) by linear interpolation between the two
+/// stops either side of it, blending in linear light and gamma-encoding to
+/// sRGB only on the way out, so the coloring stays smooth across the whole
+/// iteration range instead of banding or clipping to a single endpoint.
+pub fn color_iteration(mu: f64, color: (u8, u8, u8)) -> Rgb<u8> {
+	let stops = gradient_stops(color);
+	let t = (mu / GRADIENT_PERIOD).rem_euclid(1.0);
+	let segment = t * (stops.len() - 1) as f64;
+	let i = (segment.floor() as usize).min(stops.len() - 2);
+	let local_t = segment - i as f64;
+
+	let mut channels = [0u8; 3];
+	for (c, channel) in channels.iter_mut().enumerate() {
+		let linear = stops[i][c] + (stops[i + 1][c] - stops[i][c]) * local_t;
+		*channel = to_srgb(linear.clamp(0.0, 1.0));
+	}
+	Rgb(channels)
+}
+
+fn to_srgb(linear: f64) -> u8 {
+	let encoded = if linear <= 0.003_130_8 {
+		12.92 * linear
+	} else {
+		1.055 * linear.powf(1.0 / 2.4) - 0.055
+	};
+	(encoded.clamp(0.0, 1.0) * 255.0).round() as u8
 }
 
 pub fn render_julia(q: &RenderOptions, color: (u8, u8, u8)) -> RgbImage {
@@ -75,7 +119,7 @@ pub fn render_julia(q: &RenderOptions, color: (u8, u8, u8)) -> RgbImage {
 
 	let fill = match q.fill_style {
 		FillStyle::Black => Rgb([0; 3]),
-		FillStyle::Bright => color_iteration(q.max_iter, color),
+		FillStyle::Bright => color_iteration(q.max_iter as f64, color),
 	};
 
 	(0..q.height)
@@ -83,14 +127,12 @@ pub fn render_julia(q: &RenderOptions, color: (u8, u8, u8)) -> RgbImage {
 		.map(|y| {
 			let mut row = Vec::with_capacity(q.width);
 			for x in 0..q.width {
-				let sx = (x as f64 - width / 2.0) / ppu;
-				let sy = (y as f64 - height / 2.0) / ppu;
-				let i = julia(sx, sy, q.cx, q.cy, q.max_iter);
-
-				if i == q.max_iter {
-					row.push(fill);
-				} else {
-					row.push(color_iteration(i, color));
+				let sx = (x as f64 - width / 2.0) / ppu + q.view_cx;
+				let sy = (y as f64 - height / 2.0) / ppu + q.view_cy;
+
+				match julia(sx, sy, q.cx, q.cy, q.max_iter) {
+					Some(mu) => row.push(color_iteration(mu, color)),
+					None => row.push(fill),
 				}
 			}
 			row
@@ -106,14 +148,72 @@ pub fn render_julia(q: &RenderOptions, color: (u8, u8, u8)) -> RgbImage {
 	image
 }
 
-fn julia(mut x: f64, mut y: f64, cx: f64, cy: f64, max_iter: u16) -> u16 {
+/// Renders the Mandelbrot set: a sibling to [`render_julia`] where each
+/// pixel's own coordinate is the constant `c` and the orbit starts at the
+/// origin, so it shares the same escape-time and coloring code. `q.cx`/`q.cy`
+/// are unused here; `q.view_cx`/`q.view_cy` pan this viewport independently
+/// from the Julia preview.
+pub fn render_mandelbrot(q: &RenderOptions, color: (u8, u8, u8)) -> RgbImage {
+	let mut image = RgbImage::new(q.width as u32, q.height as u32);
+
+	let width = q.width as f64;
+	let height = q.height as f64;
+	let ppu = width / q.unit_width;
+
+	let fill = match q.fill_style {
+		FillStyle::Black => Rgb([0; 3]),
+		FillStyle::Bright => color_iteration(q.max_iter as f64, color),
+	};
+
+	(0..q.height)
+		.into_par_iter()
+		.map(|y| {
+			let mut row = Vec::with_capacity(q.width);
+			for x in 0..q.width {
+				let px = (x as f64 - width / 2.0) / ppu + q.view_cx;
+				let py = (y as f64 - height / 2.0) / ppu + q.view_cy;
+
+				match julia(0.0, 0.0, px, py, q.max_iter) {
+					Some(mu) => row.push(color_iteration(mu, color)),
+					None => row.push(fill),
+				}
+			}
+			row
+		})
+		.collect::<Vec<_>>()
+		.into_iter()
+		.enumerate()
+		.for_each(|(y, row)| {
+			for (x, i) in row.into_iter().enumerate() {
+				image.put_pixel(x as u32, y as u32, i);
+			}
+		});
+	image
+}
+
+/// Runs the escape-time iteration and returns a fractional iteration count
+/// `Some(mu)` for points that escape, or `None` for points that are still
+/// bounded at `max_iter` (i.e. considered part of the filled set).
+fn julia(mut x: f64, mut y: f64, cx: f64, cy: f64, max_iter: u16) -> Option<f64> {
 	let mut iter = 0;
-	while (x * x + y * y) < 4.0 && iter < max_iter {
+	while (x * x + y * y) < 256.0 && iter < max_iter {
 		(x, y) = (
 			x * x - y * y + cx, //
 			2.0 * x * y + cy,
 		);
 		iter += 1;
 	}
-	iter
+
+	if iter == max_iter {
+		return None;
+	}
+
+	// A couple more iterations past the bailout stabilise `r` so `mu` varies
+	// smoothly instead of jumping at each integer iteration count.
+	for _ in 0..2 {
+		(x, y) = (x * x - y * y + cx, 2.0 * x * y + cy);
+	}
+
+	let r = (x * x + y * y).sqrt();
+	Some(iter as f64 + 1.0 - r.ln().log2())
 }
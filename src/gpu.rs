@@ -0,0 +1,309 @@
+use std::sync::Arc;
+
+use eframe::glow::{self, HasContext};
+use image::{ImageBuffer, RgbImage};
+
+use crate::generate::{FillStyle, RenderOptions};
+
+const VERTEX_SHADER: &str = r#"
+	#version 140
+
+	in vec2 position;
+
+	void main() {
+		gl_Position = vec4(position, 0.0, 1.0);
+	}
+"#;
+
+// The escape loop is bounded at compile time and broken early, since GLSL
+// 140 doesn't allow a `while` condition driven entirely by a uniform.
+//
+// Mirrors `generate::{julia, color_iteration}`: a large bailout plus a
+// couple of stabilising iterations past escape gives a fractional `mu`,
+// which is sampled from the same black -> bright -> white -> bright -> black
+// gradient, blended in linear light and gamma-encoded to sRGB only at the
+// final write (glow leaves framebuffers untouched, so the shader is
+// responsible for that encoding itself).
+const FRAGMENT_SHADER: &str = r#"
+	#version 140
+
+	uniform float width;
+	uniform float height;
+	uniform float ppu;
+	uniform float view_cx;
+	uniform float view_cy;
+	uniform float cx;
+	uniform float cy;
+	uniform int max_iter;
+	uniform vec3 color;
+	uniform bool fill_black;
+
+	out vec4 frag_color;
+
+	const float GRADIENT_PERIOD = 64.0;
+
+	vec3 to_srgb(vec3 linear) {
+		vec3 lo = linear * 12.92;
+		vec3 hi = 1.055 * pow(linear, vec3(1.0 / 2.4)) - 0.055;
+		return clamp(mix(lo, hi, step(0.0031308, linear)), 0.0, 1.0);
+	}
+
+	vec3 sample_gradient(float mu, vec3 bright) {
+		vec3 stops[5] = vec3[5](vec3(0.0), bright, vec3(1.0), bright, vec3(0.0));
+		float t = fract(mu / GRADIENT_PERIOD);
+		float segment = t * 4.0;
+		int i = min(int(segment), 3);
+		return mix(stops[i], stops[i + 1], segment - float(i));
+	}
+
+	void main() {
+		float x = (gl_FragCoord.x - width / 2.0) / ppu + view_cx;
+		float y = (gl_FragCoord.y - height / 2.0) / ppu + view_cy;
+
+		int iter = 0;
+		bool escaped = false;
+		for (int i = 0; i < 4096; i++) {
+			if (i >= max_iter) {
+				break;
+			}
+			if ((x * x + y * y) >= 256.0) {
+				escaped = true;
+				break;
+			}
+			float nx = x * x - y * y + cx;
+			float ny = 2.0 * x * y + cy;
+			x = nx;
+			y = ny;
+			iter++;
+		}
+
+		if (!escaped && fill_black) {
+			frag_color = vec4(0.0, 0.0, 0.0, 1.0);
+			return;
+		}
+
+		float mu;
+		if (!escaped) {
+			mu = float(max_iter);
+		} else {
+			for (int i = 0; i < 2; i++) {
+				float nx = x * x - y * y + cx;
+				float ny = 2.0 * x * y + cy;
+				x = nx;
+				y = ny;
+			}
+			float r = sqrt(x * x + y * y);
+			mu = float(iter) + 1.0 - log2(log(r));
+		}
+
+		vec3 bright = clamp(color * 16.0 / 255.0, 0.0, 1.0);
+		frag_color = vec4(to_srgb(sample_gradient(mu, bright)), 1.0);
+	}
+"#;
+
+fn as_u8_slice(floats: &[f32]) -> &[u8] {
+	// SAFETY: `f32` has no padding/invalid bit patterns, so reading it as
+	// raw bytes for `glow::buffer_data_u8_slice` is sound.
+	unsafe { std::slice::from_raw_parts(floats.as_ptr().cast(), std::mem::size_of_val(floats)) }
+}
+
+/// GPU fragment-shader backend for [`crate::generate::render_julia`].
+///
+/// Draws a single full-screen quad into an off-screen framebuffer and runs
+/// the escape-time iteration per-pixel in the fragment shader, which makes
+/// re-rendering on every slider change cheap enough for interactive use.
+///
+/// Built on eframe's own glow context (the same one egui renders through)
+/// rather than a separate GL context: winit only supports one event loop per
+/// process, so spinning up an independent windowing/GL stack from inside
+/// `App::update` is not an option.
+pub struct GpuRenderer {
+	gl: Arc<glow::Context>,
+	program: glow::Program,
+	vao: glow::VertexArray,
+	vbo: glow::Buffer,
+	max_texture_size: u32,
+}
+
+impl GpuRenderer {
+	/// Compiles the Julia shader program on top of an existing glow context.
+	/// Fallible so callers (the GPU preview/export paths) can fall back to
+	/// the CPU renderer instead of crashing when a driver rejects the shader.
+	pub fn new(gl: Arc<glow::Context>) -> Result<Self, String> {
+		unsafe {
+			let program = gl.create_program().map_err(|e| format!("failed to create GL program: {e}"))?;
+
+			let mut compiled = Vec::new();
+			for (kind, source) in [
+				(glow::VERTEX_SHADER, VERTEX_SHADER),
+				(glow::FRAGMENT_SHADER, FRAGMENT_SHADER),
+			] {
+				let shader = gl
+					.create_shader(kind)
+					.map_err(|e| format!("failed to create shader: {e}"))?;
+				gl.shader_source(shader, source);
+				gl.compile_shader(shader);
+				if !gl.get_shader_compile_status(shader) {
+					let log = gl.get_shader_info_log(shader);
+					gl.delete_shader(shader);
+					gl.delete_program(program);
+					return Err(format!("Julia shader failed to compile: {log}"));
+				}
+				gl.attach_shader(program, shader);
+				compiled.push(shader);
+			}
+
+			gl.link_program(program);
+			for shader in compiled {
+				gl.detach_shader(program, shader);
+				gl.delete_shader(shader);
+			}
+			if !gl.get_program_link_status(program) {
+				let log = gl.get_program_info_log(program);
+				gl.delete_program(program);
+				return Err(format!("Julia shader failed to link: {log}"));
+			}
+
+			let vao = gl
+				.create_vertex_array()
+				.map_err(|e| format!("failed to create vertex array: {e}"))?;
+			let vbo = gl
+				.create_buffer()
+				.map_err(|e| format!("failed to create vertex buffer: {e}"))?;
+			gl.bind_vertex_array(Some(vao));
+			gl.bind_buffer(glow::ARRAY_BUFFER, Some(vbo));
+			let quad: [f32; 8] = [-1.0, -1.0, 1.0, -1.0, -1.0, 1.0, 1.0, 1.0];
+			gl.buffer_data_u8_slice(glow::ARRAY_BUFFER, as_u8_slice(&quad), glow::STATIC_DRAW);
+			gl.vertex_attrib_pointer_f32(0, 2, glow::FLOAT, false, 0, 0);
+			gl.enable_vertex_attrib_array(0);
+			gl.bind_vertex_array(None);
+
+			let max_texture_size = gl.get_parameter_i32(glow::MAX_TEXTURE_SIZE).max(0) as u32;
+
+			Ok(Self {
+				gl,
+				program,
+				vao,
+				vbo,
+				max_texture_size,
+			})
+		}
+	}
+
+	pub fn render(&self, q: &RenderOptions, color: (u8, u8, u8)) -> Result<RgbImage, String> {
+		let width = q.width as u32;
+		let height = q.height as u32;
+		if width > self.max_texture_size || height > self.max_texture_size {
+			return Err(format!(
+				"requested render {width}x{height} exceeds this GPU's max texture size of {}",
+				self.max_texture_size
+			));
+		}
+		let ppu = q.width as f32 / q.unit_width as f32;
+		let gl = &self.gl;
+
+		unsafe {
+			let texture = gl
+				.create_texture()
+				.map_err(|e| format!("failed to create GPU texture: {e}"))?;
+			gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+			gl.tex_image_2d(
+				glow::TEXTURE_2D,
+				0,
+				glow::RGB as i32,
+				width as i32,
+				height as i32,
+				0,
+				glow::RGB,
+				glow::UNSIGNED_BYTE,
+				None,
+			);
+			gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_MIN_FILTER, glow::NEAREST as i32);
+			gl.tex_parameter_i32(glow::TEXTURE_2D, glow::TEXTURE_MAG_FILTER, glow::NEAREST as i32);
+
+			let framebuffer = gl
+				.create_framebuffer()
+				.map_err(|e| format!("failed to create GPU framebuffer: {e}"))?;
+			gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+			gl.framebuffer_texture_2d(
+				glow::FRAMEBUFFER,
+				glow::COLOR_ATTACHMENT0,
+				glow::TEXTURE_2D,
+				Some(texture),
+				0,
+			);
+
+			let status = gl.check_framebuffer_status(glow::FRAMEBUFFER);
+			if status != glow::FRAMEBUFFER_COMPLETE {
+				gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+				gl.delete_framebuffer(framebuffer);
+				gl.delete_texture(texture);
+				return Err(format!("GPU framebuffer incomplete (status {status:#x})"));
+			}
+
+			gl.viewport(0, 0, width as i32, height as i32);
+			gl.use_program(Some(self.program));
+			gl.uniform_1_f32(gl.get_uniform_location(self.program, "width").as_ref(), width as f32);
+			gl.uniform_1_f32(gl.get_uniform_location(self.program, "height").as_ref(), height as f32);
+			gl.uniform_1_f32(gl.get_uniform_location(self.program, "ppu").as_ref(), ppu);
+			gl.uniform_1_f32(
+				gl.get_uniform_location(self.program, "view_cx").as_ref(),
+				q.view_cx as f32,
+			);
+			gl.uniform_1_f32(
+				gl.get_uniform_location(self.program, "view_cy").as_ref(),
+				q.view_cy as f32,
+			);
+			gl.uniform_1_f32(gl.get_uniform_location(self.program, "cx").as_ref(), q.cx as f32);
+			gl.uniform_1_f32(gl.get_uniform_location(self.program, "cy").as_ref(), q.cy as f32);
+			gl.uniform_1_i32(
+				gl.get_uniform_location(self.program, "max_iter").as_ref(),
+				q.max_iter as i32,
+			);
+			gl.uniform_3_f32(
+				gl.get_uniform_location(self.program, "color").as_ref(),
+				color.0 as f32,
+				color.1 as f32,
+				color.2 as f32,
+			);
+			gl.uniform_1_i32(
+				gl.get_uniform_location(self.program, "fill_black").as_ref(),
+				(q.fill_style == FillStyle::Black) as i32,
+			);
+
+			gl.bind_vertex_array(Some(self.vao));
+			gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+
+			let mut pixels = vec![0u8; (width * height * 3) as usize];
+			gl.read_pixels(
+				0,
+				0,
+				width as i32,
+				height as i32,
+				glow::RGB,
+				glow::UNSIGNED_BYTE,
+				glow::PixelPackData::Slice(&mut pixels),
+			);
+
+			gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+			gl.delete_framebuffer(framebuffer);
+			gl.delete_texture(texture);
+
+			// `gl_FragCoord.y` and `read_pixels` both use GL's bottom-left
+			// origin, so the two conventions cancel out and this already
+			// matches `render_julia`'s top-down row order without a flip.
+			ImageBuffer::from_raw(width, height, pixels)
+				.ok_or_else(|| "GPU framebuffer had unexpected pixel layout".to_string())
+		}
+	}
+}
+
+impl Drop for GpuRenderer {
+	fn drop(&mut self) {
+		unsafe {
+			self.gl.delete_program(self.program);
+			self.gl.delete_vertex_array(self.vao);
+			self.gl.delete_buffer(self.vbo);
+		}
+	}
+}
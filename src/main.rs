@@ -9,16 +9,18 @@ use std::{
 };
 
 use eframe::{
-	egui::{self, DragValue, Slider, TextureOptions},
+	egui::{self, DragValue, Sense, Slider, TextureOptions},
 	epaint::{TextureHandle, Vec2},
 	Frame, NativeOptions,
 };
-use generate::{render_c, render_julia, FillStyle, RenderOptions};
-use image::EncodableLayout;
+use generate::{render_c, render_julia, render_mandelbrot, FillStyle, RenderOptions};
+use gpu::GpuRenderer;
+use image::{EncodableLayout, RgbImage};
 use native_dialog::FileDialog;
 use serde::{Deserialize, Serialize};
 
 mod generate;
+mod gpu;
 
 const SETTINGS_FILE: &str = "fractal_settings.json";
 
@@ -44,11 +46,23 @@ struct JuliaGUI {
 	#[serde(alias = "export_iterations")]
 	export_max_iter: u16,
 	preview_point: bool,
+	#[serde(default)]
+	use_gpu_preview: bool,
+	#[serde(default)]
+	pick_c_mode: bool,
 	#[serde(default = "default_color_presets")]
 	color_presets: Vec<(String, (u8, u8, u8))>,
+	#[serde(default = "default_mandelbrot_settings")]
+	mandelbrot_settings: RenderOptions,
 	#[serde(skip)]
 	preview: Option<TextureHandle>,
 	#[serde(skip)]
+	mandelbrot_preview: Option<TextureHandle>,
+	#[serde(skip)]
+	mandelbrot_changed: bool,
+	#[serde(skip)]
+	gpu_renderer: Option<GpuRenderer>,
+	#[serde(skip)]
 	preview_render_ms: f64,
 	#[serde(skip)]
 	export_render_ms: Option<f64>,
@@ -79,18 +93,77 @@ fn default_color_presets() -> Vec<(String, (u8, u8, u8))> {
 	]
 }
 
+fn default_mandelbrot_settings() -> RenderOptions {
+	RenderOptions {
+		width: 256,
+		height: 256,
+		unit_width: 3.0,
+		view_cx: -0.5,
+		view_cy: 0.0,
+		max_iter: 128,
+		cx: 0.0,
+		cy: 0.0,
+		fill_style: FillStyle::Black,
+	}
+}
+
 enum RenderJob {
 	Render(PathBuf, RenderOptions, (u8, u8, u8)),
 	Exit,
 }
 
+/// Pans/zooms `target`'s `view_cx`/`view_cy`/`unit_width` from drag and
+/// scroll on `response`, keeping the point under the cursor fixed while
+/// zooming. Returns whether `target` changed, and the plane coordinate under
+/// the cursor on a plain click, so callers can use it to seed a Julia `c`.
+fn pan_zoom_and_pick(
+	target: &mut RenderOptions,
+	ctx: &egui::Context,
+	response: &egui::Response,
+	size: Vec2,
+) -> (bool, Option<(f64, f64)>) {
+	let mut changed = false;
+	let ppu = target.width as f32 / target.unit_width as f32;
+
+	if response.dragged() {
+		let delta = response.drag_delta();
+		target.view_cx -= (delta.x / ppu) as f64;
+		target.view_cy -= (delta.y / ppu) as f64;
+		changed = true;
+	}
+
+	let Some(pointer) = response.hover_pos() else {
+		return (changed, None);
+	};
+	let local = pointer - response.rect.left_top();
+	let plane_x = (local.x - size.x / 2.0) / ppu + target.view_cx as f32;
+	let plane_y = (local.y - size.y / 2.0) / ppu + target.view_cy as f32;
+
+	let scroll = ctx.input(|i| i.scroll_delta.y);
+	if scroll != 0.0 {
+		let zoom = (-scroll * 0.001).exp();
+		let new_unit_width = (target.unit_width * zoom as f64).clamp(0.01, 50.0);
+		let new_ppu = target.width as f32 / new_unit_width as f32;
+		target.view_cx = plane_x as f64 - ((local.x - size.x / 2.0) / new_ppu) as f64;
+		target.view_cy = plane_y as f64 - ((local.y - size.y / 2.0) / new_ppu) as f64;
+		target.unit_width = new_unit_width;
+		changed = true;
+	}
+
+	let picked = response.clicked().then_some((plane_x as f64, plane_y as f64));
+	(changed, picked)
+}
+
 impl Default for JuliaGUI {
 	fn default() -> Self {
 		Self {
 			color: (12, 5, 10),
 			color_presets: default_color_presets(),
+			mandelbrot_settings: default_mandelbrot_settings(),
 			new_color_preset_name: String::new(),
 			preview: None,
+			mandelbrot_preview: None,
+			mandelbrot_changed: true,
 			settings: RenderOptions::default(),
 			preview_render_ms: 0.0,
 			export_render_ms: None,
@@ -99,6 +172,9 @@ impl Default for JuliaGUI {
 			export_path: PathBuf::new(),
 			settings_changed: true,
 			preview_point: false,
+			use_gpu_preview: false,
+			pick_c_mode: false,
+			gpu_renderer: None,
 			render_thread_handle: None,
 			render_thread: None,
 			render_result: None,
@@ -120,6 +196,11 @@ impl JuliaGUI {
 			egui::ColorImage::from_rgb([1, 1], &[0, 0, 0]),
 			TextureOptions::default(),
 		);
+		let mandelbrot_preview = cc.egui_ctx.load_texture(
+			"mandelbrot_preview_image",
+			egui::ColorImage::from_rgb([1, 1], &[0, 0, 0]),
+			TextureOptions::default(),
+		);
 
 		let (job_sender, job_receiver) = mpsc::channel::<RenderJob>();
 		let (result_sender, result_receiver) = mpsc::channel::<f64>();
@@ -144,7 +225,9 @@ impl JuliaGUI {
 			.unwrap();
 
 		n.preview = Some(preview);
+		n.mandelbrot_preview = Some(mandelbrot_preview);
 		n.settings_changed = true;
+		n.mandelbrot_changed = true;
 		n.export_path = "julia_fractal.png".into();
 		n.render_thread_handle = Some(render_thread);
 		n.render_thread = Some(job_sender);
@@ -158,14 +241,53 @@ impl JuliaGUI {
 		file.write_all(settings.as_bytes()).unwrap();
 	}
 
-	fn update_preview(&mut self) {
+	/// Lazily builds the GPU renderer from `frame`'s glow context (shared with
+	/// eframe's own rendering, since a second GL context can't be created
+	/// from inside `App::update`) and uses it to render `q`. Returns `Err`
+	/// instead of panicking so callers can fall back to the CPU renderer.
+	fn gpu_render(&mut self, frame: &Frame, q: &RenderOptions, color: (u8, u8, u8)) -> Result<RgbImage, String> {
+		if self.gpu_renderer.is_none() {
+			let gl = frame
+				.gl()
+				.ok_or_else(|| "no glow GL context available on this eframe backend".to_string())?;
+			self.gpu_renderer = Some(GpuRenderer::new(gl.clone())?);
+		}
+		self.gpu_renderer.as_ref().unwrap().render(q, color)
+	}
+
+	fn update_preview(&mut self, frame: &Frame) {
 		let start_time = SystemTime::now();
-		let mut frame = render_julia(&self.settings, self.color);
+		let mut frame_img = if self.use_gpu_preview {
+			match self.gpu_render(frame, &self.settings.clone(), self.color) {
+				Ok(image) => image,
+				Err(err) => {
+					println!("GPU preview failed, falling back to CPU: {err}");
+					self.use_gpu_preview = false;
+					render_julia(&self.settings, self.color)
+				}
+			}
+		} else {
+			render_julia(&self.settings, self.color)
+		};
 		if self.preview_point {
-			frame = render_c(&self.settings, frame);
+			frame_img = render_c(&self.settings, frame_img);
 		}
 
 		if let Some(preview) = &mut self.preview {
+			preview.set(
+				egui::ColorImage::from_rgb(
+					[frame_img.width() as usize, frame_img.height() as usize],
+					frame_img.as_bytes(),
+				),
+				TextureOptions::default(),
+			);
+		}
+		self.preview_render_ms = start_time.elapsed().unwrap().as_micros() as f64 / 1000.0;
+	}
+
+	fn update_mandelbrot_preview(&mut self) {
+		let frame = render_mandelbrot(&self.mandelbrot_settings, self.color);
+		if let Some(preview) = &mut self.mandelbrot_preview {
 			preview.set(
 				egui::ColorImage::from_rgb(
 					[frame.width() as usize, frame.height() as usize],
@@ -174,20 +296,42 @@ impl JuliaGUI {
 				TextureOptions::default(),
 			);
 		}
-		self.preview_render_ms = start_time.elapsed().unwrap().as_micros() as f64 / 1000.0;
 	}
 
-	fn export_render(&mut self) {
+	fn export_render(&mut self, frame: &Frame) {
 		self.save_settings();
-		if let Some(channel) = &self.render_thread {
-			let res_mul = 1 << self.export_res_power;
-			let settings = RenderOptions {
-				width: self.settings.width * res_mul,
-				height: self.settings.height * res_mul,
-				max_iter: self.export_max_iter,
-				..self.settings.clone()
-			};
+		let res_mul = 1 << self.export_res_power;
+		let settings = RenderOptions {
+			width: self.settings.width * res_mul,
+			height: self.settings.height * res_mul,
+			max_iter: self.export_max_iter,
+			..self.settings.clone()
+		};
+
+		// The GPU context lives on the UI thread, so the GPU path renders and
+		// saves synchronously rather than going through the render thread
+		// like the CPU path below. At high export resolutions this can
+		// exceed the GPU's max texture size, so a failure here (including
+		// that one) falls back to the CPU path instead of blocking or
+		// crashing the UI thread.
+		if self.use_gpu_preview {
+			let start_time = SystemTime::now();
+			match self.gpu_render(frame, &settings, self.color) {
+				Ok(image) => {
+					if let Err(err) = image.save(&self.export_path) {
+						println!("Failed to save render: {err}");
+					}
+					self.export_render_ms =
+						Some(start_time.elapsed().unwrap().as_micros() as f64 / 1000.0);
+					return;
+				}
+				Err(err) => {
+					println!("GPU export failed, falling back to CPU: {err}");
+				}
+			}
+		}
 
+		if let Some(channel) = &self.render_thread {
 			channel
 				.send(RenderJob::Render(
 					self.export_path.clone(),
@@ -199,25 +343,59 @@ impl JuliaGUI {
 		}
 	}
 
-	fn export_render_new_path(&mut self) {
+	/// Converts pointer interaction with the main preview into pan/zoom of
+	/// `settings.view_cx`/`view_cy`/`unit_width`, or a picked `cx`/`cy` when
+	/// `pick_c_mode` is on.
+	fn handle_preview_interaction(&mut self, ctx: &egui::Context, response: &egui::Response, size: Vec2) {
+		let (changed, picked) = pan_zoom_and_pick(&mut self.settings, ctx, response, size);
+		self.settings_changed |= changed;
+
+		if self.pick_c_mode {
+			if let Some((x, y)) = picked {
+				self.settings.cx = x;
+				self.settings.cy = y;
+				self.settings_changed = true;
+			}
+		}
+	}
+
+	/// Same pan/zoom as the main preview, but over `mandelbrot_settings`'s own
+	/// view; a plain click always seeds the Julia constant from the hovered
+	/// point, giving the usual paired Mandelbrot/Julia exploration workflow.
+	fn handle_mandelbrot_interaction(&mut self, ctx: &egui::Context, response: &egui::Response, size: Vec2) {
+		let (changed, picked) = pan_zoom_and_pick(&mut self.mandelbrot_settings, ctx, response, size);
+		self.mandelbrot_changed |= changed;
+
+		if let Some((x, y)) = picked {
+			self.settings.cx = x;
+			self.settings.cy = y;
+			self.settings_changed = true;
+		}
+	}
+
+	fn export_render_new_path(&mut self, frame: &Frame) {
 		if let Ok(Some(path)) = FileDialog::new()
 			.set_filename(&self.export_path.to_string_lossy())
 			.add_filter("PNG file", &["png"])
 			.show_save_single_file()
 		{
 			self.export_path = path;
-			self.export_render();
+			self.export_render(frame);
 		}
 	}
 }
 
 impl eframe::App for JuliaGUI {
-	fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
+	fn update(&mut self, ctx: &egui::Context, frame: &mut Frame) {
 		if self.settings_changed {
-			self.update_preview();
+			self.update_preview(frame);
 			self.save_settings();
 			self.settings_changed = false;
 		}
+		if self.mandelbrot_changed {
+			self.update_mandelbrot_preview();
+			self.mandelbrot_changed = false;
+		}
 
 		if let Some(result) = self.render_result.as_mut().and_then(|r| r.try_recv().ok()) {
 			self.export_render_ms = Some(result);
@@ -234,6 +412,8 @@ impl eframe::App for JuliaGUI {
 				));
 
 				let set_point_vis = ui.checkbox(&mut self.preview_point, "View C point");
+				let set_gpu_preview = ui.checkbox(&mut self.use_gpu_preview, "GPU preview");
+				ui.checkbox(&mut self.pick_c_mode, "Pick C by clicking preview");
 				ui.label("C point (X, Y):");
 				let set_cx =
 					ui.add(Slider::new(&mut self.settings.cx, -1.0..=1.0).clamp_to_range(false));
@@ -261,6 +441,7 @@ impl eframe::App for JuliaGUI {
 								if ui.button(name).clicked() {
 									self.color = *col;
 									self.settings_changed = true;
+									self.mandelbrot_changed = true;
 								}
 								if ui.button("x").clicked() {
 									to_remove = Some(i);
@@ -277,6 +458,7 @@ impl eframe::App for JuliaGUI {
 								rand::random::<u8>() & 15,
 							);
 							self.settings_changed = true;
+							self.mandelbrot_changed = true;
 						}
 						ui.horizontal(|ui| {
 							ui.text_edit_singleline(&mut self.new_color_preset_name);
@@ -350,10 +532,10 @@ impl eframe::App for JuliaGUI {
 							"Render"
 						};
 						if ui.button(export_text).clicked() {
-							self.export_render();
+							self.export_render(frame);
 						}
 						if ui.button("Render to").clicked() {
-							self.export_render_new_path();
+							self.export_render_new_path(frame);
 						}
 					});
 					if self.waiting {
@@ -395,16 +577,37 @@ impl eframe::App for JuliaGUI {
 					|| set_red.changed() || set_green.changed()
 					|| set_blue.changed()
 					|| set_point_vis.changed()
+					|| set_gpu_preview.changed()
 				{
 					self.settings_changed = true;
 				}
+				if set_red.changed() || set_green.changed() || set_blue.changed() {
+					self.mandelbrot_changed = true;
+				}
 			});
 
 		egui::CentralPanel::default().show(ctx, |ui| {
 			if let Some(texture) = &self.preview {
-				ui.image(texture, texture.size_vec2());
+				let size = texture.size_vec2();
+				let response = ui.add(
+					egui::Image::new(texture, size).sense(Sense::click_and_drag()),
+				);
+				self.handle_preview_interaction(ctx, &response, size);
 			}
 		});
+
+		egui::Window::new("Mandelbrot map")
+			.default_pos([1000.0, 50.0])
+			.show(ctx, |ui| {
+				if let Some(texture) = &self.mandelbrot_preview {
+					let size = texture.size_vec2();
+					let response = ui.add(
+						egui::Image::new(texture, size).sense(Sense::click_and_drag()),
+					);
+					self.handle_mandelbrot_interaction(ctx, &response, size);
+					ui.label("click to set the Julia C point");
+				}
+			});
 	}
 
 	fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {